@@ -0,0 +1,95 @@
+//! `gorc` Abscissa Application type.
+//!
+//! Holds the loaded [`CliConfig`] so that subcommands (e.g. `eth balance`,
+//! `eth contract`) can read Ethereum endpoint/contract settings through the
+//! application context instead of hard-coding them. The config itself is
+//! loaded from a TOML file by [`boot`], which `EntryPoint::run` calls
+//! before dispatching to a subcommand; see [`crate::entrypoint`] for the
+//! `config_path`/`process_config` machinery that drives it.
+
+use crate::config::CliConfig;
+use crate::entrypoint::EntryPoint;
+use abscissa_core::{
+    application::{self, AppCell},
+    Application, Configurable, FrameworkError, StandardPaths,
+};
+use std::process;
+
+/// Application state for `gorc`.
+#[derive(Debug)]
+pub struct GorcApp {
+    /// Loaded configuration, merged from TOML plus any `--config`
+    /// overrides applied before a subcommand runs.
+    config: CliConfig,
+
+    /// Standard framework application state.
+    state: application::State<Self>,
+}
+
+impl Default for GorcApp {
+    fn default() -> Self {
+        Self {
+            config: CliConfig::default(),
+            state: application::State::default(),
+        }
+    }
+}
+
+impl Application for GorcApp {
+    type Cmd = crate::entrypoint::EntryPoint;
+    type Cfg = CliConfig;
+    type Paths = StandardPaths;
+
+    fn config(&self) -> &CliConfig {
+        &self.config
+    }
+
+    fn state(&self) -> &application::State<Self> {
+        &self.state
+    }
+}
+
+/// The global application instance, following Abscissa's singleton pattern.
+pub static APP: AppCell<GorcApp> = AppCell::new();
+
+/// Replaces the loaded configuration, e.g. after merging `--config
+/// key=value` overrides on top of the parsed TOML file.
+pub fn set_config(config: CliConfig) -> Result<(), FrameworkError> {
+    APP.write(|app| app.config = config);
+    Ok(())
+}
+
+/// Loads the TOML config file `entry` points at (if any), applies
+/// `entry`'s `--config` overrides via [`Configurable::process_config`],
+/// and installs the result as the running config. Exits the process when
+/// the file exists but cannot be read or is not valid TOML rather than
+/// silently falling back to defaults; when no file is found at all
+/// (`config_path` returns `None`), [`CliConfig::default`] is used.
+///
+/// Called once, at the very start of [`EntryPoint::run`][crate::entrypoint::EntryPoint],
+/// before any subcommand sees `APP.config()`.
+pub fn boot(entry: &EntryPoint) {
+    let config = match entry.config_path() {
+        Some(path) => {
+            let contents = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("error reading config file '{}': {}", path.display(), e);
+                process::exit(1);
+            });
+            toml::from_str(&contents).unwrap_or_else(|e| {
+                eprintln!("error parsing config file '{}': {}", path.display(), e);
+                process::exit(1);
+            })
+        }
+        None => CliConfig::default(),
+    };
+
+    let config = entry.process_config(config).unwrap_or_else(|e| {
+        eprintln!("error processing config: {}", e);
+        process::exit(1);
+    });
+
+    if let Err(e) = set_config(config) {
+        eprintln!("error installing config: {}", e);
+        process::exit(1);
+    }
+}
@@ -1,55 +1,774 @@
 //! `eth subcommands` subcommand
 
-use abscissa_core::{Command, Options, Runnable};
+use crate::application::APP;
+use crate::config::CliConfig;
+use abscissa_core::{Application, Command, Options, Runnable};
+use clarity::abi::{encode_call, Token};
+use clarity::{Address, Uint256};
+use sha3::{Digest, Keccak256};
+use std::fs;
+use std::process;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, instrument};
+use tracing_subscriber::EnvFilter;
+use web30::client::Web3;
+use web30::types::NewFilter;
 
+/// Fallback JSON-RPC endpoint, used only when neither `--rpc-url` nor the
+/// `[ethereum] rpc` config field is set.
+const DEFAULT_ETH_RPC_URL: &str = "http://localhost:8545";
+
+/// How long to wait on a single Ethereum RPC call before giving up.
+const ETH_RPC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Resolves the Ethereum RPC URL to use, preferring a command-line
+/// override over the `[ethereum] rpc` config field, then a built-in
+/// fallback. Takes `config` explicitly (rather than reading `APP.config()`
+/// itself) so it's a pure function callers can unit test.
+fn effective_rpc_url(flag: &Option<String>, config: &CliConfig) -> String {
+    flag.clone()
+        .or_else(|| config.ethereum.rpc.clone())
+        .unwrap_or_else(|| DEFAULT_ETH_RPC_URL.to_string())
+}
+
+/// Resolves the Gravity bridge ERC20 token address, preferring a
+/// command-line override over the `[ethereum] erc20_address` config
+/// field. Unlike [`effective_rpc_url`], and like
+/// [`effective_contract_address`], there is no sensible built-in fallback here: silently defaulting to
+/// the zero address would make `eth balance` print a confident-looking
+/// `ERC20 balance: 0` that's indistinguishable from a real empty
+/// balance, so an unconfigured address is reported as an error instead.
+fn effective_erc20_address(flag: &Option<String>, config: &CliConfig) -> Result<String, String> {
+    flag.clone()
+        .or_else(|| config.ethereum.erc20_address.clone())
+        .ok_or_else(|| {
+            "no ERC20 address configured, pass --erc20 or set [ethereum] erc20_address".to_string()
+        })
+}
+
+/// Resolves the Gravity bridge contract address, preferring a
+/// command-line override over the `[ethereum] contract_address` config
+/// field. Like [`effective_erc20_address`], there is no sensible built-in
+/// fallback here: `eth contract call` can move funds, so silently
+/// defaulting to the zero address would risk signing and broadcasting a
+/// transaction against the burn address instead of failing fast.
+fn effective_contract_address(flag: &Option<String>, config: &CliConfig) -> Result<String, String> {
+    flag.clone()
+        .or_else(|| config.ethereum.contract_address.clone())
+        .ok_or_else(|| {
+            "no contract address configured, pass --contract or set [ethereum] contract_address"
+                .to_string()
+        })
+}
+
+/// Installs the global tracing subscriber for this invocation, honoring
+/// `RUST_LOG` (via the loaded `[trace]` config) and upgrading to the
+/// `debug` filter when `--verbose` is passed. Safe to call more than once
+/// per process; later calls are no-ops.
+///
+/// Only reached through [`crate::entrypoint::EntryPoint`], which owns the
+/// top-level `--verbose` flag; `--config` overrides are merged earlier,
+/// in `EntryPoint`'s own `Configurable::process_config`.
+pub(crate) fn init_tracing(verbose: bool) {
+    let filter = if verbose {
+        "debug".to_string()
+    } else {
+        APP.config().trace.filter.clone()
+    };
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new(filter))
+        .try_init();
+}
 
 #[derive(Command, Debug, Options)]
-pub enum Eth{
+pub enum Eth {
     #[options(help = "balance [key-name]")]
     Balance(Balance),
+
+    #[options(help = "deploy, call or watch logs on the Gravity contract")]
+    Contract(Contract),
 }
 
 impl Runnable for Eth {
     /// Start the application.
     fn run(&self) {
-        // Your code goes here
+        match self {
+            Eth::Balance(cmd) => cmd.run(),
+            Eth::Contract(cmd) => cmd.run(),
+        }
     }
 }
 
 #[derive(Command, Debug, Options)]
-pub struct Balance{
+pub struct Balance {
     #[options(free)]
     free: Vec<String>,
 
     #[options(help = "print help message")]
     help: bool,
 
+    #[options(help = "Ethereum JSON-RPC endpoint to query", meta = "URL")]
+    rpc_url: Option<String>,
+
+    #[options(
+        help = "an ERC20 contract address to check the balance of; required unless [ethereum] erc20_address is set",
+        meta = "ADDRESS"
+    )]
+    erc20: Option<String>,
+
+    #[options(
+        help = "block number to query the balance at, or \"latest\" (default)",
+        meta = "NUMBER|latest"
+    )]
+    block: Option<String>,
+
+    #[options(help = "print output as JSON")]
+    json: bool,
 }
 
+/// The balances returned for a single address.
+#[derive(Debug, serde::Serialize)]
+struct BalanceOutput {
+    address: String,
+    block: String,
+    eth_balance: String,
+    erc20_token: String,
+    erc20_balance: String,
+}
 
 impl Runnable for Balance {
     fn run(&self) {
-        assert!(self.free.len() == 1);
+        if self.free.len() != 1 {
+            eprintln!("error: expected exactly one key name or address");
+            process::exit(1);
+        }
         let key_name = self.free[0].clone();
+
+        let rpc_url = effective_rpc_url(&self.rpc_url, APP.config());
+        let erc20 = effective_erc20_address(&self.erc20, APP.config()).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        });
+        let block = self.block.clone().unwrap_or_else(|| "latest".to_string());
+        let json = self.json;
+
+        let runtime = tokio::runtime::Runtime::new().expect("could not start Tokio runtime");
+        runtime.block_on(async move {
+            if let Err(e) = print_balances(&key_name, &rpc_url, &erc20, &block, json).await {
+                eprintln!("error querying balance: {}", e);
+                process::exit(1);
+            }
+        });
+    }
+}
+
+/// Converts a block number into the `0x`-prefixed hex quantity Ethereum's
+/// JSON-RPC methods expect for a block parameter. Tags like `"latest"` are
+/// not handled here; `print_balances` passes them through itself.
+fn block_param_hex(block: u128) -> String {
+    format!("0x{:x}", block)
+}
+
+/// Resolves a `key-name` to the Ethereum address it refers to.
+///
+/// A literal address is used as-is; anything else is looked up through the
+/// configured key backend, without decrypting or otherwise touching the
+/// signing key itself - a balance query is read-only and shouldn't need
+/// to unlock one.
+fn resolve_address(key_name: &str) -> Result<Address, String> {
+    if let Ok(address) = Address::parse_and_validate(key_name) {
+        return Ok(address);
     }
+
+    crate::keys::resolve_address(key_name)
 }
 
+#[instrument(skip(json), fields(rpc_url))]
+async fn print_balances(
+    key_name: &str,
+    rpc_url: &str,
+    erc20: &str,
+    block: &str,
+    json: bool,
+) -> Result<(), String> {
+    let address = resolve_address(key_name)?;
+    let erc20_address =
+        Address::parse_and_validate(erc20).map_err(|_| format!("'{}' is not a valid ERC20 contract address", erc20))?;
+
+    let block_param = if block == "latest" {
+        block.to_string()
+    } else {
+        let number: u128 = block
+            .parse()
+            .map_err(|_| format!("'{}' is not \"latest\" or a valid block number", block))?;
+        block_param_hex(number)
+    };
 
+    let web3 = Web3::new(rpc_url, ETH_RPC_TIMEOUT);
+
+    let start = Instant::now();
+    let eth_balance = web3
+        .eth_get_balance(address, &block_param)
+        .await
+        .map_err(|e| format!("failed to query ETH balance: {}", e))?;
+    debug!(elapsed = ?start.elapsed(), "eth_getBalance round-trip");
+
+    let start = Instant::now();
+    let erc20_balance = web3
+        .get_erc20_balance(erc20_address, address, &block_param)
+        .await
+        .map_err(|e| format!("failed to query ERC20 balance: {}", e))?;
+    debug!(elapsed = ?start.elapsed(), "erc20 balanceOf round-trip");
+
+    let output = BalanceOutput {
+        address: address.to_string(),
+        block: block.to_string(),
+        eth_balance: eth_balance.to_string(),
+        erc20_token: erc20_address.to_string(),
+        erc20_balance: erc20_balance.to_string(),
+    };
+    info!(address = %output.address, "queried balances");
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&output).expect("failed to serialize balance output")
+        );
+    } else {
+        println!("Address:      {}", output.address);
+        println!("Block:        {}", output.block);
+        println!("ETH balance:  {}", output.eth_balance);
+        println!("ERC20 token:  {}", output.erc20_token);
+        println!("ERC20 balance:{}", output.erc20_balance);
+    }
+
+    Ok(())
+}
 
 #[derive(Command, Debug, Options)]
-pub struct Contract{
-    #[options(free)]
+pub enum Contract {
+    #[options(help = "deploy the Gravity bridge contract")]
+    Deploy(ContractDeploy),
+
+    #[options(help = "call a method on the Gravity bridge contract")]
+    Call(ContractCall),
+
+    #[options(help = "stream SendToCosmos / TransactionBatchExecuted event logs")]
+    Logs(ContractLogs),
+}
+
+impl Runnable for Contract {
+    fn run(&self) {
+        match self {
+            Contract::Deploy(cmd) => cmd.run(),
+            Contract::Call(cmd) => cmd.run(),
+            Contract::Logs(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Command, Debug, Options)]
+pub struct ContractDeploy {
+    #[options(free, help = "path to a file containing the contract's deployment bytecode (hex)")]
     free: Vec<String>,
 
     #[options(help = "print help message")]
     help: bool,
 
+    #[options(help = "name of the key to deploy from", meta = "KEY-NAME")]
+    from: Option<String>,
+
+    #[options(help = "Ethereum JSON-RPC endpoint to deploy to", meta = "URL")]
+    rpc_url: Option<String>,
+
+    #[options(help = "print output as JSON")]
+    json: bool,
 }
 
-impl Runnable for Contract {
-    /// Start the application.
+impl Runnable for ContractDeploy {
     fn run(&self) {
-       
+        if self.free.len() != 1 {
+            eprintln!("error: expected a path to the contract bytecode");
+            process::exit(1);
+        }
+        let bytecode_path = self.free[0].clone();
+        let from = self.from.clone();
+        let rpc_url = effective_rpc_url(&self.rpc_url, APP.config());
+        let json = self.json;
 
+        let runtime = tokio::runtime::Runtime::new().expect("could not start Tokio runtime");
+        runtime.block_on(async move {
+            if let Err(e) = deploy_contract(&bytecode_path, from, &rpc_url, json).await {
+                eprintln!("error deploying contract: {}", e);
+                process::exit(1);
+            }
+        });
     }
-}
\ No newline at end of file
+}
+
+/// The result of a successful contract deployment.
+#[derive(Debug, serde::Serialize)]
+struct DeployOutput {
+    deployer: String,
+    contract_address: String,
+    tx_hash: String,
+}
+
+#[instrument(skip_all, fields(bytecode_path, rpc_url))]
+async fn deploy_contract(bytecode_path: &str, from: Option<String>, rpc_url: &str, json: bool) -> Result<(), String> {
+    let from_key = from.ok_or_else(|| "deploying requires a --from key name".to_string())?;
+    let signer = crate::keys::load_signing_key(&from_key)?;
+    debug!(deployer = %signer.address(), "loaded signing key");
+
+    let bytecode = fs::read_to_string(bytecode_path)
+        .map_err(|e| format!("failed to read bytecode from '{}': {}", bytecode_path, e))?;
+    let bytecode = clarity::utils::hex_str_to_bytes(bytecode.trim())
+        .map_err(|e| format!("'{}' does not contain valid hex bytecode: {}", bytecode_path, e))?;
+
+    let web3 = Web3::new(rpc_url, ETH_RPC_TIMEOUT);
+
+    let start = Instant::now();
+    let (tx_hash, contract_address) = web3
+        .deploy_contract(bytecode, signer.address(), &signer.expose_for_signing(), vec![])
+        .await
+        .map_err(|e| format!("failed to deploy contract: {}", e))?;
+    debug!(elapsed = ?start.elapsed(), "deployment round-trip");
+
+    let output = DeployOutput {
+        deployer: signer.address().to_string(),
+        contract_address: contract_address.to_string(),
+        tx_hash: tx_hash.to_string(),
+    };
+    info!(contract_address = %output.contract_address, tx_hash = %output.tx_hash, "deployed contract");
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&output).expect("failed to serialize deploy output")
+        );
+    } else {
+        println!("Deployer:         {}", output.deployer);
+        println!("Contract address: {}", output.contract_address);
+        println!("Transaction hash: {}", output.tx_hash);
+    }
+
+    Ok(())
+}
+
+#[derive(Command, Debug, Options)]
+pub struct ContractCall {
+    #[options(free, help = "call <method-name> [type:value...], e.g. call sendToCosmos address:0x... bytes32:0x... uint256:1000")]
+    free: Vec<String>,
+
+    #[options(help = "print help message")]
+    help: bool,
+
+    #[options(help = "the Gravity contract address to call, or set [ethereum] contract_address", meta = "ADDRESS")]
+    contract: Option<String>,
+
+    #[options(help = "name of the key to call from", meta = "KEY-NAME")]
+    from: Option<String>,
+
+    #[options(help = "amount of ETH (in wei) to send with the call", meta = "WEI")]
+    value: Option<String>,
+
+    #[options(help = "Ethereum JSON-RPC endpoint to call against", meta = "URL")]
+    rpc_url: Option<String>,
+
+    #[options(help = "print output as JSON")]
+    json: bool,
+}
+
+impl Runnable for ContractCall {
+    fn run(&self) {
+        if self.free.is_empty() {
+            eprintln!("error: expected a method name, e.g. call <method> [args...]");
+            process::exit(1);
+        }
+        let contract = effective_contract_address(&self.contract, APP.config()).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        });
+        let method = self.free[0].clone();
+        let args = self.free[1..].to_vec();
+        let from = self.from.clone();
+        let value = self.value.clone();
+        let rpc_url = effective_rpc_url(&self.rpc_url, APP.config());
+        let json = self.json;
+
+        let runtime = tokio::runtime::Runtime::new().expect("could not start Tokio runtime");
+        runtime.block_on(async move {
+            if let Err(e) = call_contract(&contract, &method, &args, from, value, &rpc_url, json).await {
+                eprintln!("error calling contract: {}", e);
+                process::exit(1);
+            }
+        });
+    }
+}
+
+/// The result of a successful contract call.
+#[derive(Debug, serde::Serialize)]
+struct CallOutput {
+    caller: String,
+    contract: String,
+    method: String,
+    tx_hash: String,
+}
+
+/// Parses a single `call` positional argument of the form `type:value`
+/// into an ABI token, e.g. `address:0x1234...`, `uint256:1000`, or
+/// `bytes32:0xdead...`. A bare value with no `type:` prefix is rejected
+/// rather than guessed at, since guessing wrong (e.g. always encoding as
+/// `string`) produces a call that reverts or silently does the wrong
+/// thing on the real contract.
+fn parse_call_arg(arg: &str) -> Result<Token, String> {
+    let (ty, value) = arg
+        .split_once(':')
+        .ok_or_else(|| format!("argument '{}' is missing a type prefix, e.g. 'address:0x...'", arg))?;
+
+    match ty {
+        "address" => Address::parse_and_validate(value)
+            .map(Token::Address)
+            .map_err(|e| format!("'{}' is not a valid address: {}", value, e)),
+        "uint256" => value
+            .parse::<Uint256>()
+            .map(Token::Uint)
+            .map_err(|_| format!("'{}' is not a valid uint256", value)),
+        "bool" => value
+            .parse::<bool>()
+            .map(Token::Bool)
+            .map_err(|_| format!("'{}' is not a valid bool, expected true or false", value)),
+        "bytes32" => {
+            let bytes = clarity::utils::hex_str_to_bytes(value)
+                .map_err(|e| format!("'{}' is not valid hex: {}", value, e))?;
+            let array: [u8; 32] = bytes
+                .try_into()
+                .map_err(|_| format!("'{}' must be exactly 32 bytes for bytes32", value))?;
+            Ok(Token::FixedBytes(array.to_vec()))
+        }
+        "bytes" => clarity::utils::hex_str_to_bytes(value)
+            .map(Token::Bytes)
+            .map_err(|e| format!("'{}' is not valid hex: {}", value, e)),
+        "string" => Ok(Token::String(value.to_string())),
+        other => Err(format!(
+            "unsupported argument type '{}', expected address, uint256, bool, bytes32, bytes or string",
+            other
+        )),
+    }
+}
+
+/// Builds the full `name(type1,type2,...)` signature `encode_call` hashes
+/// to derive the 4-byte function selector. Passing just `method` would
+/// hash the bare name instead of the canonical signature, producing a
+/// wrong selector for every method that takes arguments; each `type:value`
+/// arg has already had its type prefix validated by [`parse_call_arg`] by
+/// the time this is called, so the `split_once` here can't fail.
+fn call_signature(method: &str, args: &[String]) -> String {
+    let types: Vec<&str> = args.iter().map(|a| a.split_once(':').expect("validated by parse_call_arg").0).collect();
+    format!("{}({})", method, types.join(","))
+}
+
+#[instrument(skip(args, from, value, json), fields(contract, method, rpc_url))]
+async fn call_contract(
+    contract: &str,
+    method: &str,
+    args: &[String],
+    from: Option<String>,
+    value: Option<String>,
+    rpc_url: &str,
+    json: bool,
+) -> Result<(), String> {
+    let from_key = from.ok_or_else(|| "calling a contract requires a --from key name".to_string())?;
+    let signer = crate::keys::load_signing_key(&from_key)?;
+    debug!(caller = %signer.address(), "loaded signing key");
+
+    let contract_address =
+        Address::parse_and_validate(contract).map_err(|_| format!("'{}' is not a valid contract address", contract))?;
+
+    let value: Uint256 = value
+        .unwrap_or_else(|| "0".to_string())
+        .parse()
+        .map_err(|_| "--value must be an integer amount of wei".to_string())?;
+
+    let tokens = args.iter().map(|a| parse_call_arg(a)).collect::<Result<Vec<Token>, String>>()?;
+    let signature = call_signature(method, args);
+    let calldata = encode_call(&signature, &tokens).map_err(|e| format!("failed to ABI-encode call: {}", e))?;
+
+    let web3 = Web3::new(rpc_url, ETH_RPC_TIMEOUT);
+
+    let start = Instant::now();
+    let tx_hash = web3
+        .send_transaction(contract_address, calldata, value, signer.address(), &signer.expose_for_signing(), vec![])
+        .await
+        .map_err(|e| format!("failed to send transaction: {}", e))?;
+    debug!(elapsed = ?start.elapsed(), "send transaction round-trip");
+
+    let output = CallOutput {
+        caller: signer.address().to_string(),
+        contract: contract_address.to_string(),
+        method: method.to_string(),
+        tx_hash: tx_hash.to_string(),
+    };
+    info!(tx_hash = %output.tx_hash, "sent contract call");
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&output).expect("failed to serialize call output")
+        );
+    } else {
+        println!("Caller:           {}", output.caller);
+        println!("Contract:         {}", output.contract);
+        println!("Method:           {}", output.method);
+        println!("Transaction hash: {}", output.tx_hash);
+    }
+
+    Ok(())
+}
+
+#[derive(Command, Debug, Options)]
+pub struct ContractLogs {
+    #[options(free, help = "logs <SendToCosmos|TransactionBatchExecuted>")]
+    free: Vec<String>,
+
+    #[options(help = "print help message")]
+    help: bool,
+
+    #[options(help = "the Gravity contract address to watch", meta = "ADDRESS")]
+    contract: Option<String>,
+
+    #[options(help = "first block to fetch logs from", meta = "NUMBER")]
+    from_block: Option<u128>,
+
+    #[options(help = "last block to fetch logs from, defaults to latest", meta = "NUMBER")]
+    to_block: Option<u128>,
+
+    #[options(help = "Ethereum JSON-RPC endpoint to query", meta = "URL")]
+    rpc_url: Option<String>,
+
+    #[options(help = "print output as JSON")]
+    json: bool,
+}
+
+impl Runnable for ContractLogs {
+    fn run(&self) {
+        if self.free.len() != 1 {
+            eprintln!("error: expected one event name, SendToCosmos or TransactionBatchExecuted");
+            process::exit(1);
+        }
+        let event = self.free[0].clone();
+        let contract = effective_contract_address(&self.contract, APP.config()).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        });
+        let from_block = self.from_block.unwrap_or(0);
+        let to_block = self.to_block;
+        let rpc_url = effective_rpc_url(&self.rpc_url, APP.config());
+        let json = self.json;
+
+        let runtime = tokio::runtime::Runtime::new().expect("could not start Tokio runtime");
+        runtime.block_on(async move {
+            if let Err(e) = stream_logs(&event, &contract, from_block, to_block, &rpc_url, json).await {
+                eprintln!("error fetching logs: {}", e);
+                process::exit(1);
+            }
+        });
+    }
+}
+
+/// Returns the Keccak-256 event signature topic for one of the Gravity
+/// contract's events.
+fn event_topic(event: &str) -> Result<String, String> {
+    let signature = match event {
+        "SendToCosmos" => "SendToCosmosEvent(address,address,bytes32,uint256,uint256)",
+        "TransactionBatchExecuted" => "TransactionBatchExecutedEvent(uint256,address,uint256)",
+        other => return Err(format!("unknown event '{}', expected SendToCosmos or TransactionBatchExecuted", other)),
+    };
+    let mut hasher = Keccak256::new();
+    hasher.update(signature.as_bytes());
+    Ok(format!("0x{}", hex::encode(hasher.finalize())))
+}
+
+#[instrument(skip(json), fields(event, contract, rpc_url))]
+async fn stream_logs(
+    event: &str,
+    contract: &str,
+    from_block: u128,
+    to_block: Option<u128>,
+    rpc_url: &str,
+    json: bool,
+) -> Result<(), String> {
+    let contract_address =
+        Address::parse_and_validate(contract).map_err(|_| format!("'{}' is not a valid contract address", contract))?;
+    let topic = event_topic(event)?;
+
+    let web3 = Web3::new(rpc_url, ETH_RPC_TIMEOUT);
+    let filter = NewFilter {
+        address: vec![contract_address],
+        topics: Some(vec![Some(vec![topic])]),
+        from_block: Some(block_param_hex(from_block)),
+        to_block: to_block.map(block_param_hex),
+    };
+
+    let start = Instant::now();
+    let logs = web3
+        .eth_get_logs(filter)
+        .await
+        .map_err(|e| format!("failed to fetch logs: {}", e))?;
+    debug!(elapsed = ?start.elapsed(), count = logs.len(), "eth_getLogs round-trip");
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&logs).expect("failed to serialize logs")
+        );
+    } else {
+        for log in logs {
+            println!("{:?}", log);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_topic_known_events() {
+        assert_eq!(
+            event_topic("SendToCosmos").unwrap(),
+            format!(
+                "0x{}",
+                hex::encode(Keccak256::digest(b"SendToCosmosEvent(address,address,bytes32,uint256,uint256)"))
+            )
+        );
+        assert_eq!(
+            event_topic("TransactionBatchExecuted").unwrap(),
+            format!(
+                "0x{}",
+                hex::encode(Keccak256::digest(b"TransactionBatchExecutedEvent(uint256,address,uint256)"))
+            )
+        );
+    }
+
+    #[test]
+    fn event_topic_rejects_unknown_event() {
+        let err = event_topic("SomeOtherEvent").unwrap_err();
+        assert!(err.contains("unknown event"));
+    }
+
+    #[test]
+    fn effective_rpc_url_prefers_flag_then_config_then_default() {
+        let mut config = CliConfig::default();
+        assert_eq!(effective_rpc_url(&None, &config), DEFAULT_ETH_RPC_URL);
+
+        config.ethereum.rpc = Some("http://configured:8545".to_string());
+        assert_eq!(effective_rpc_url(&None, &config), "http://configured:8545");
+        assert_eq!(
+            effective_rpc_url(&Some("http://flag:8545".to_string()), &config),
+            "http://flag:8545"
+        );
+    }
+
+    #[test]
+    fn effective_erc20_address_prefers_flag_then_config_then_errors() {
+        let mut config = CliConfig::default();
+        let err = effective_erc20_address(&None, &config).unwrap_err();
+        assert!(err.contains("no ERC20 address configured"));
+
+        config.ethereum.erc20_address = Some("0xaaa".to_string());
+        assert_eq!(effective_erc20_address(&None, &config).unwrap(), "0xaaa");
+        assert_eq!(
+            effective_erc20_address(&Some("0xbbb".to_string()), &config).unwrap(),
+            "0xbbb"
+        );
+    }
+
+    #[test]
+    fn block_param_hex_encodes_as_0x_prefixed_hex() {
+        assert_eq!(block_param_hex(0), "0x0");
+        assert_eq!(block_param_hex(12345), "0x3039");
+    }
+
+    #[test]
+    fn print_balances_rejects_non_latest_non_numeric_block() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let err = runtime
+            .block_on(print_balances(
+                "0x0000000000000000000000000000000000000abc",
+                "http://localhost:1",
+                "0x0000000000000000000000000000000000000def",
+                "not-a-block",
+                false,
+            ))
+            .unwrap_err();
+        assert!(err.contains("is not \"latest\" or a valid block number"));
+    }
+
+    #[test]
+    fn parse_call_arg_parses_each_supported_type() {
+        assert_eq!(
+            parse_call_arg("address:0x0000000000000000000000000000000000000abc").unwrap(),
+            Token::Address(Address::parse_and_validate("0x0000000000000000000000000000000000000abc").unwrap())
+        );
+        assert_eq!(parse_call_arg("uint256:1000").unwrap(), Token::Uint(1000u32.into()));
+        assert_eq!(parse_call_arg("bool:true").unwrap(), Token::Bool(true));
+        assert_eq!(
+            parse_call_arg("bytes32:0x00000000000000000000000000000000000000000000000000000000000000ff").unwrap(),
+            Token::FixedBytes(vec![
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff
+            ])
+        );
+        assert_eq!(parse_call_arg("bytes:0xdead").unwrap(), Token::Bytes(vec![0xde, 0xad]));
+        assert_eq!(parse_call_arg("string:hello").unwrap(), Token::String("hello".to_string()));
+    }
+
+    #[test]
+    fn call_signature_builds_full_signature_from_arg_types() {
+        assert_eq!(
+            call_signature(
+                "sendToCosmos",
+                &[
+                    "address:0xabc".to_string(),
+                    "bytes32:0xdead".to_string(),
+                    "uint256:1000".to_string()
+                ]
+            ),
+            "sendToCosmos(address,bytes32,uint256)"
+        );
+    }
+
+    #[test]
+    fn call_signature_of_a_zero_arg_method_has_empty_parens() {
+        assert_eq!(call_signature("pause", &[]), "pause()");
+    }
+
+    #[test]
+    fn parse_call_arg_rejects_missing_type_prefix() {
+        let err = parse_call_arg("1000").unwrap_err();
+        assert!(err.contains("missing a type prefix"));
+    }
+
+    #[test]
+    fn parse_call_arg_rejects_unsupported_type() {
+        let err = parse_call_arg("int128:1000").unwrap_err();
+        assert!(err.contains("unsupported argument type"));
+    }
+
+    #[test]
+    fn effective_contract_address_prefers_flag_then_config_then_errors() {
+        let mut config = CliConfig::default();
+        let err = effective_contract_address(&None, &config).unwrap_err();
+        assert!(err.contains("no contract address configured"));
+
+        config.ethereum.contract_address = Some("0xccc".to_string());
+        assert_eq!(effective_contract_address(&None, &config).unwrap(), "0xccc");
+        assert_eq!(
+            effective_contract_address(&Some("0xddd".to_string()), &config).unwrap(),
+            "0xddd"
+        );
+    }
+}
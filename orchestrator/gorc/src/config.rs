@@ -0,0 +1,164 @@
+//! `gorc` configuration types.
+//!
+//! Configuration is loaded from a TOML file following Abscissa's standard
+//! config subsystem (see [`crate::entrypoint::EntryPoint`]'s `Configurable`
+//! impl), then selectively overridden by the top-level `--config` flag and
+//! by dedicated command-line flags on the individual `eth` subcommands.
+
+use serde::{Deserialize, Serialize};
+
+/// Top-level configuration for `gorc`.
+///
+/// Implements Abscissa's blanket `Config` trait by virtue of being
+/// `Clone + Debug + Default + Serialize + DeserializeOwned`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct CliConfig {
+    /// Ethereum-related configuration.
+    #[serde(default)]
+    pub ethereum: EthereumConfig,
+
+    /// Logging/tracing configuration.
+    #[serde(default)]
+    pub trace: TraceConfig,
+
+    /// Signing key configuration.
+    #[serde(default)]
+    pub keys: KeysConfig,
+}
+
+impl CliConfig {
+    /// Applies a single `dotted.key=value` override, as parsed from a
+    /// `--config` flag, in place.
+    pub fn apply_override(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "ethereum.rpc" => self.ethereum.rpc = Some(value.to_string()),
+            "ethereum.contract_address" => self.ethereum.contract_address = Some(value.to_string()),
+            "ethereum.erc20_address" => self.ethereum.erc20_address = Some(value.to_string()),
+            "trace.filter" => self.trace.filter = value.to_string(),
+            "keys.keystore_path" => self.keys.keystore_path = Some(value.to_string()),
+            "keys.backend" => {
+                self.keys.backend = match value {
+                    "keystore" => KeyBackend::Keystore,
+                    "env" => KeyBackend::Env,
+                    other => return Err(format!("'{}' is not a valid key backend, expected keystore or env", other)),
+                }
+            }
+            other => return Err(format!("unknown config key '{}'", other)),
+        }
+        Ok(())
+    }
+}
+
+/// The `[ethereum]` TOML section.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EthereumConfig {
+    /// JSON-RPC endpoint used to reach an Ethereum node.
+    pub rpc: Option<String>,
+
+    /// Address of the deployed Gravity bridge contract.
+    pub contract_address: Option<String>,
+
+    /// Address of the Gravity bridge ERC20 token, used as the default for
+    /// `eth balance`.
+    pub erc20_address: Option<String>,
+}
+
+/// Logging configuration, analogous to Abscissa's own `trace::Config`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TraceConfig {
+    /// A `tracing-subscriber` filter directive, e.g. `"info"` or
+    /// `"gorc=debug"`. Defaults to the `RUST_LOG` environment variable,
+    /// falling back to `"info"` if it isn't set.
+    pub filter: String,
+}
+
+impl Default for TraceConfig {
+    fn default() -> Self {
+        Self {
+            filter: std::env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string()),
+        }
+    }
+}
+
+/// The `[keys]` TOML section, selecting how signing keys are loaded.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct KeysConfig {
+    /// Which backend to resolve `key-name` arguments against.
+    #[serde(default)]
+    pub backend: KeyBackend,
+
+    /// Directory of encrypted keystore files, one per key, named
+    /// `<key-name>.json`. Only used when `backend = "keystore"`.
+    pub keystore_path: Option<String>,
+}
+
+/// Where `eth` subcommands load private key material from.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyBackend {
+    /// An encrypted JSON keystore file on disk, unlocked with the
+    /// `GORC_KEYSTORE_PASSPHRASE` environment variable.
+    Keystore,
+
+    /// A raw hex private key read from an environment variable named
+    /// `GORC_KEY_<KEY_NAME>`.
+    Env,
+}
+
+impl Default for KeyBackend {
+    fn default() -> Self {
+        KeyBackend::Keystore
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_override_sets_known_keys() {
+        let mut config = CliConfig::default();
+
+        config.apply_override("ethereum.rpc", "http://example:8545").unwrap();
+        config.apply_override("ethereum.contract_address", "0xabc").unwrap();
+        config.apply_override("ethereum.erc20_address", "0xdef").unwrap();
+        config.apply_override("trace.filter", "gorc=debug").unwrap();
+        config.apply_override("keys.keystore_path", "/keys").unwrap();
+        config.apply_override("keys.backend", "env").unwrap();
+
+        assert_eq!(config.ethereum.rpc.as_deref(), Some("http://example:8545"));
+        assert_eq!(config.ethereum.contract_address.as_deref(), Some("0xabc"));
+        assert_eq!(config.ethereum.erc20_address.as_deref(), Some("0xdef"));
+        assert_eq!(config.trace.filter, "gorc=debug");
+        assert_eq!(config.keys.keystore_path.as_deref(), Some("/keys"));
+        assert!(matches!(config.keys.backend, KeyBackend::Env));
+    }
+
+    #[test]
+    fn apply_override_rejects_unknown_key() {
+        let mut config = CliConfig::default();
+        let err = config.apply_override("ethereum.bogus", "x").unwrap_err();
+        assert!(err.contains("unknown config key"));
+    }
+
+    #[test]
+    fn apply_override_rejects_invalid_key_backend() {
+        let mut config = CliConfig::default();
+        let err = config.apply_override("keys.backend", "ldap").unwrap_err();
+        assert!(err.contains("not a valid key backend"));
+    }
+
+    #[test]
+    fn config_parses_without_an_ethereum_section() {
+        let config: CliConfig = toml::from_str("[trace]\nfilter = \"gorc=debug\"\n").unwrap();
+        assert_eq!(config.ethereum.rpc, None);
+        assert_eq!(config.trace.filter, "gorc=debug");
+
+        let config: CliConfig = toml::from_str("").unwrap();
+        assert_eq!(config.ethereum.contract_address, None);
+    }
+}
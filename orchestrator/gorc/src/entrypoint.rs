@@ -0,0 +1,105 @@
+//! `gorc` top-level entry point.
+//!
+//! Abscissa's own CLI apps put flags that apply regardless of which
+//! subcommand is chosen on a wrapper around the command tree rather than
+//! on every leaf command. This mirrors that: [`EntryPoint`] carries the
+//! repeatable, Cargo-style `--config key=value` override and `--verbose`,
+//! so both can be given *before* the subcommand name (`gorc --config
+//! ethereum.rpc=... balance my-key`), and [`Eth`] and its subactions no
+//! longer each need their own copy of the same two flags.
+//!
+//! [`EntryPoint`] is also where the `[ethereum]`/`[trace]`/`[keys]` TOML
+//! config file actually gets loaded, via [`Configurable`]: `config_path`
+//! says where to look, and `process_config` folds the `--config`
+//! overrides onto whatever was parsed from it, following Abscissa's
+//! "overriding configuration settings using command-line options" model.
+
+use crate::application::GorcApp;
+use crate::commands::query::eth::{init_tracing, Eth};
+use crate::config::CliConfig;
+use abscissa_core::{Command, Configurable, FrameworkError, Options, Runnable};
+use std::path::PathBuf;
+use std::process;
+
+/// Default location of the TOML config file, relative to the current
+/// directory. Overridden with the `GORC_CONFIG` environment variable.
+///
+/// This is deliberately a different knob from `--config`: following
+/// Cargo's `--config net.git-fetch-with-cli=true`, `--config` here is
+/// reserved for one-off inline `key=value` overrides, not for pointing at
+/// a config file.
+const DEFAULT_CONFIG_FILE: &str = "gorc.toml";
+
+#[derive(Command, Debug, Options)]
+pub struct EntryPoint {
+    #[options(command)]
+    pub cmd: Option<Eth>,
+
+    #[options(help = "print help message")]
+    pub help: bool,
+
+    #[options(
+        help = "override a config value, e.g. --config ethereum.rpc=http://...; may be given more than once",
+        meta = "KEY=VALUE"
+    )]
+    pub config: Vec<String>,
+
+    #[options(help = "enable debug logging")]
+    pub verbose: bool,
+}
+
+impl Configurable<GorcApp> for EntryPoint {
+    /// Looks for `gorc.toml` in the current directory, or wherever
+    /// `GORC_CONFIG` points. Returns `None` (no error) when it isn't
+    /// there, so running with no config file at all still works, falling
+    /// back to [`CliConfig::default`] plus any `--config`/dedicated-flag
+    /// overrides.
+    fn config_path(&self) -> Option<PathBuf> {
+        let path = std::env::var("GORC_CONFIG")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(DEFAULT_CONFIG_FILE));
+
+        if path.exists() {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    /// Merges `--config key=value` overrides onto the config loaded from
+    /// `config_path()` (or the default, if there was no file). A
+    /// malformed pair or unknown key exits the process rather than
+    /// continuing with a half-applied config, matching how the rest of
+    /// `eth`'s CLI validation is reported.
+    fn process_config(&self, mut config: CliConfig) -> Result<CliConfig, FrameworkError> {
+        for pair in &self.config {
+            let (key, value) = match pair.split_once('=') {
+                Some(kv) => kv,
+                None => {
+                    eprintln!("error applying --config overrides: '{}' is not in the form KEY=VALUE", pair);
+                    process::exit(1);
+                }
+            };
+            if let Err(e) = config.apply_override(key, value) {
+                eprintln!("error applying --config overrides: {}", e);
+                process::exit(1);
+            }
+        }
+        Ok(config)
+    }
+}
+
+impl Runnable for EntryPoint {
+    fn run(&self) {
+        crate::application::boot(self);
+        init_tracing(self.verbose);
+
+        match &self.cmd {
+            Some(cmd) => cmd.run(),
+            None => {
+                eprintln!("error: no subcommand given, try `balance` or `contract`");
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Signing key resolution.
+//!
+//! `eth` subcommands take a `key-name` that must resolve to private key
+//! material for signing transactions (`contract deploy`/`contract call`).
+//! The key is loaded through one of the backends configured under
+//! `[keys]` and is always handed back wrapped in a [`secrecy::Secret`], so
+//! it is zeroized on drop and never appears in `Debug`/log output. The
+//! intermediate buffers the backends decode into (a `Vec<u8>` from the
+//! keystore decryptor, a raw hex `String` from the environment) are
+//! explicitly zeroized too, so no unwrapped copy of the key survives past
+//! [`SigningKey::from_bytes`].
+
+use crate::application::APP;
+use crate::config::KeyBackend;
+use clarity::{Address, PrivateKey};
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+use zeroize::Zeroize;
+
+/// Loads the signing key for `key_name` using the configured backend.
+pub fn load_signing_key(key_name: &str) -> Result<SigningKey, String> {
+    match APP.config().keys.backend {
+        KeyBackend::Keystore => load_from_keystore(key_name),
+        KeyBackend::Env => load_from_env(key_name),
+    }
+}
+
+/// Resolves the address `key_name` signs for, without decrypting or
+/// otherwise touching the private key material.
+///
+/// Read-only commands like `eth balance` only need an address, not
+/// signing capability; going through [`load_signing_key`] for that would
+/// force unlocking a keystore passphrase (or reading a raw private key
+/// out of the environment) just to answer a balance query, undermining
+/// the whole point of keeping that material behind a [`Secret`].
+pub fn resolve_address(key_name: &str) -> Result<Address, String> {
+    match APP.config().keys.backend {
+        KeyBackend::Keystore => address_from_keystore_file(key_name),
+        KeyBackend::Env => address_from_env(key_name),
+    }
+}
+
+/// The subset of a V3 keystore JSON file this cares about. The address is
+/// stored in plaintext alongside the encrypted `crypto` section
+/// specifically so tooling can look it up without a passphrase.
+#[derive(Deserialize)]
+struct KeystoreAddress {
+    address: String,
+}
+
+fn address_from_keystore_file(key_name: &str) -> Result<Address, String> {
+    let dir = APP
+        .config()
+        .keys
+        .keystore_path
+        .clone()
+        .ok_or_else(|| "the keystore backend is selected but [keys] keystore_path is not set".to_string())?;
+    let path = std::path::Path::new(&dir).join(format!("{}.json", key_name));
+
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read keystore entry '{}': {}", key_name, e))?;
+    let entry: KeystoreAddress = serde_json::from_str(&contents)
+        .map_err(|e| format!("'{}' is not a valid keystore file: {}", path.display(), e))?;
+
+    let address = entry.address.trim_start_matches("0x");
+    Address::parse_and_validate(format!("0x{}", address))
+        .map_err(|e| format!("keystore entry '{}' has an invalid address: {}", key_name, e))
+}
+
+fn address_from_env(key_name: &str) -> Result<Address, String> {
+    let var_name = format!("GORC_ADDRESS_{}", sanitize_env_suffix(key_name));
+    let value = std::env::var(&var_name)
+        .map_err(|_| format!("expected the address for '{}' in the {} environment variable", key_name, var_name))?;
+    Address::parse_and_validate(&value).map_err(|e| format!("{} is not a valid address: {}", var_name, e))
+}
+
+fn load_from_keystore(key_name: &str) -> Result<SigningKey, String> {
+    let dir = APP
+        .config()
+        .keys
+        .keystore_path
+        .clone()
+        .ok_or_else(|| "the keystore backend is selected but [keys] keystore_path is not set".to_string())?;
+    let path = std::path::Path::new(&dir).join(format!("{}.json", key_name));
+    let passphrase = std::env::var("GORC_KEYSTORE_PASSPHRASE")
+        .map_err(|_| "GORC_KEYSTORE_PASSPHRASE must be set to unlock the keystore".to_string())?;
+
+    let bytes = eth_keystore::decrypt_key(&path, &passphrase)
+        .map_err(|e| format!("failed to decrypt keystore entry '{}': {}", key_name, e))?;
+
+    SigningKey::from_bytes(bytes)
+}
+
+fn load_from_env(key_name: &str) -> Result<SigningKey, String> {
+    let var_name = format!("GORC_KEY_{}", sanitize_env_suffix(key_name));
+    let mut hex_key = std::env::var(&var_name)
+        .map_err(|_| format!("expected a hex private key in the {} environment variable", var_name))?;
+    let bytes = clarity::utils::hex_str_to_bytes(hex_key.trim())
+        .map_err(|e| format!("{} does not contain a valid hex private key: {}", var_name, e));
+    hex_key.zeroize();
+
+    SigningKey::from_bytes(bytes?)
+}
+
+fn sanitize_env_suffix(key_name: &str) -> String {
+    key_name
+        .to_uppercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// A private key loaded for signing.
+///
+/// The key material is held in a [`Secret`] so it is zeroized on drop.
+/// `Debug` is implemented by hand to redact it; only [`SigningKey::address`]
+/// and [`SigningKey::expose_for_signing`] give access to anything derived
+/// from the secret.
+pub struct SigningKey {
+    secret: Secret<[u8; 32]>,
+    address: Address,
+}
+
+impl SigningKey {
+    /// Takes ownership of the decoded key material so it can be zeroized
+    /// here rather than left for the allocator once the caller's `Vec`
+    /// goes out of scope.
+    fn from_bytes(mut bytes: Vec<u8>) -> Result<Self, String> {
+        if bytes.len() != 32 {
+            bytes.zeroize();
+            return Err("private key must be exactly 32 bytes".to_string());
+        }
+        let mut array = [0u8; 32];
+        array.copy_from_slice(&bytes);
+        bytes.zeroize();
+
+        let private_key = match PrivateKey::from_slice(&array) {
+            Ok(private_key) => private_key,
+            Err(e) => {
+                array.zeroize();
+                return Err(format!("invalid private key: {}", e));
+            }
+        };
+        let address = private_key.to_address();
+
+        let secret = Secret::new(array);
+        array.zeroize();
+
+        Ok(Self { secret, address })
+    }
+
+    /// The address this key signs for. Safe to log.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Exposes the raw key material for signing a transaction. Callers
+    /// must not log, print, or otherwise persist the returned value.
+    pub fn expose_for_signing(&self) -> PrivateKey {
+        PrivateKey::from_slice(self.secret.expose_secret()).expect("validated on construction")
+    }
+}
+
+impl std::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("address", &self.address)
+            .field("secret", &"[redacted]")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_env_suffix_uppercases_alphanumerics() {
+        assert_eq!(sanitize_env_suffix("validator"), "VALIDATOR");
+    }
+
+    #[test]
+    fn sanitize_env_suffix_replaces_non_alphanumerics() {
+        assert_eq!(sanitize_env_suffix("my-key.1"), "MY_KEY_1");
+    }
+
+    #[test]
+    fn sanitize_env_suffix_is_idempotent_on_already_valid_names() {
+        assert_eq!(sanitize_env_suffix("VALIDATOR_1"), "VALIDATOR_1");
+    }
+}